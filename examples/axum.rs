@@ -12,7 +12,7 @@ use axum::{
 };
 use jsend::JSendResponse;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use serde_json::{json, Value};
 use uuid::Uuid;
 
 #[derive(Serialize, Debug, Clone)]
@@ -56,7 +56,7 @@ async fn list_posts(State(db): State<Db>) -> impl IntoResponse {
         posts.push(post);
     }
 
-    Json(JSendResponse::success(Some(json!({"posts": posts}))))
+    JSendResponse::<Value>::success(Some(json!({"posts": posts})))
 }
 
 async fn create_post(State(db): State<Db>, Json(input): Json<CreatePost>) -> impl IntoResponse {
@@ -70,18 +70,15 @@ async fn create_post(State(db): State<Db>, Json(input): Json<CreatePost>) -> imp
 
     db.write().unwrap().insert(post.id, post);
 
-    Json(JSendResponse::success(Some(json!({"id": id}))))
+    JSendResponse::<Value>::success(Some(json!({"id": id}))).with_status(StatusCode::CREATED)
 }
 
 async fn get_post_by_id(Path(id): Path<Uuid>, State(db): State<Db>) -> impl IntoResponse {
     let db = db.read().unwrap();
-    let post = db.get(&id);
-    match post {
-        Some(post) => Json(JSendResponse::success(Some(json!({"post": post})))).into_response(),
-        None => (
-            StatusCode::NOT_FOUND,
-            Json(JSendResponse::fail(json!({"id": "not found"}))),
-        )
+    match db.get(&id) {
+        Some(post) => JSendResponse::<Value>::success(Some(json!({"post": post}))).into_response(),
+        None => JSendResponse::<(), Value>::fail(json!({"id": "not found"}))
+            .with_status(StatusCode::NOT_FOUND)
             .into_response(),
     }
 }
@@ -89,5 +86,5 @@ async fn get_post_by_id(Path(id): Path<Uuid>, State(db): State<Db>) -> impl Into
 async fn delete_post_by_id(Path(id): Path<Uuid>, State(db): State<Db>) -> impl IntoResponse {
     let mut db = db.write().unwrap();
     db.remove(&id).unwrap();
-    Json(JSendResponse::success(None::<()>))
+    JSendResponse::<()>::success(None)
 }
@@ -0,0 +1,79 @@
+//! Integration with [axum](https://crates.io/crates/axum), gated behind the
+//! `axum` feature.
+//!
+//! Implements [axum::response::IntoResponse] for [JSendResponse] so handlers
+//! can return a [JSendResponse] directly instead of manually pairing it with
+//! a [StatusCode].
+
+use axum::{http::StatusCode, response::IntoResponse, Json};
+use serde::Serialize;
+
+use crate::JSendResponse;
+
+impl<S, F, E> IntoResponse for JSendResponse<S, F, E>
+where
+    S: Serialize,
+    F: Serialize,
+    E: Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        let status = default_status(&self);
+        (status, Json(self)).into_response()
+    }
+}
+
+/// Maps a [JSendResponse] to the [StatusCode] it should be served with when
+/// no explicit override is given via [JSendResponse::with_status].
+///
+/// - `Success` maps to `200 OK`.
+/// - `Fail` maps to `400 Bad Request`.
+/// - `Error` maps to `code` when it is a valid 4xx/5xx status, and
+///   `500 Internal Server Error` otherwise.
+fn default_status<S, F, E>(response: &JSendResponse<S, F, E>) -> StatusCode {
+    match response {
+        JSendResponse::Success { .. } => StatusCode::OK,
+        JSendResponse::Fail { .. } => StatusCode::BAD_REQUEST,
+        JSendResponse::Error { code, .. } => code
+            .and_then(|code| u16::try_from(code).ok())
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .filter(|status| status.is_client_error() || status.is_server_error())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+impl<S, F, E> JSendResponse<S, F, E> {
+    /// Pairs this response with an explicit [StatusCode], overriding the
+    /// default status [JSendResponse::into_response] would otherwise pick.
+    ///
+    /// ```rust
+    /// # use axum::http::StatusCode;
+    /// # use jsend::{JSendResponse, JSendResponseWithStatus};
+    /// let response: JSendResponseWithStatus<&str> =
+    ///     JSendResponse::success(Some("created")).with_status(StatusCode::CREATED);
+    /// ```
+    pub fn with_status(self, status: StatusCode) -> JSendResponseWithStatus<S, F, E> {
+        JSendResponseWithStatus {
+            response: self,
+            status,
+        }
+    }
+}
+
+/// A [JSendResponse] paired with an explicit [StatusCode], returned by
+/// [JSendResponse::with_status].
+#[derive(Debug, Clone)]
+pub struct JSendResponseWithStatus<S, F = serde_json::Value, E = serde_json::Value> {
+    response: JSendResponse<S, F, E>,
+    status: StatusCode,
+}
+
+impl<S, F, E> IntoResponse for JSendResponseWithStatus<S, F, E>
+where
+    S: Serialize,
+    F: Serialize,
+    E: Serialize,
+{
+    fn into_response(self) -> axum::response::Response {
+        (self.status, Json(self.response)).into_response()
+    }
+}
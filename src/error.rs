@@ -0,0 +1,83 @@
+//! Bridges application error types into [JSendResponse::Error].
+
+use std::error::Error as StdError;
+
+use serde_json::Value;
+
+use crate::JSendResponse;
+
+/// Bridges an application's error type into a [JSendResponse::Error].
+///
+/// Implement this for a central `thiserror`-derived error enum to get a
+/// [JSendResponse] for free via [IntoJSendError::to_jsend_error] (or the
+/// blanket [From] impl below, so `?`/`.into()` works directly). Override
+/// [IntoJSendError::code] on a variant-by-variant basis to surface a numeric
+/// status code; override [IntoJSendError::data] to attach structured
+/// diagnostics instead of the default `source()` chain.
+///
+/// There is deliberately no blanket `impl<E: std::error::Error> IntoJSendError
+/// for E`: stable Rust has no specialization, so a blanket impl would make it
+/// a coherence error for any error type to override [IntoJSendError::code] or
+/// [IntoJSendError::data] -- exactly the per-variant customization this trait
+/// exists for. Implementing the trait for your own type is a one-line
+/// `impl IntoJSendError for MyError {}` when the defaults suffice.
+pub trait IntoJSendError: StdError + 'static {
+    /// The numeric `code` to attach to the resulting `Error` response.
+    ///
+    /// Returns `None` by default; override per-variant for errors that carry
+    /// a meaningful numeric code.
+    fn code(&self) -> Option<i64> {
+        None
+    }
+
+    /// Structured `data` to attach to the resulting `Error` response.
+    ///
+    /// Defaults to this error's [source](StdError::source) chain, serialized
+    /// as a JSON array of each cause's [Display](std::fmt::Display)
+    /// representation, or `None` if there is no source.
+    fn data(&self) -> Option<Value>
+    where
+        Self: Sized,
+    {
+        source_chain(self)
+    }
+
+    /// Converts this error into a [JSendResponse::Error], populating
+    /// `message` from this error's [Display](std::fmt::Display)
+    /// representation and `code`/`data` from [IntoJSendError::code] and
+    /// [IntoJSendError::data].
+    ///
+    /// `S` and `F` (the `Success`/`Fail` payload types) are free -- an
+    /// `Error` response never carries either -- so they're picked by the
+    /// caller, typically via a type annotation or the blanket [From] impl
+    /// below.
+    fn to_jsend_error<S, F>(&self) -> JSendResponse<S, F, Value>
+    where
+        Self: Sized,
+    {
+        JSendResponse::error(self.to_string(), self.code(), self.data())
+    }
+}
+
+impl<T, S, F> From<T> for JSendResponse<S, F, Value>
+where
+    T: IntoJSendError,
+{
+    fn from(error: T) -> Self {
+        error.to_jsend_error()
+    }
+}
+
+fn source_chain(error: &(dyn StdError + 'static)) -> Option<Value> {
+    let mut causes = Vec::new();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        causes.push(Value::from(cause.to_string()));
+        source = cause.source();
+    }
+    if causes.is_empty() {
+        None
+    } else {
+        Some(Value::from(causes))
+    }
+}
@@ -19,11 +19,12 @@
 //!
 //! // Success response with data
 //! let data = Some(HashMap::from([("key", "value")]));
-//! let response = JSendResponse::success(data);
+//! let response: JSendResponse<_> = JSendResponse::success(data);
 //! println!("{}", serde_json::to_string(&response).unwrap());
 //!
 //! // Error response
-//! let error_response = JSendResponse::error("An error occurred".to_string(), Some(100), None::<String>);
+//! let error_response: JSendResponse<()> =
+//!     JSendResponse::error("An error occurred".to_string(), Some(100), None);
 //! println!("{}", serde_json::to_string(&error_response).unwrap());
 //! ```
 //!
@@ -35,6 +36,34 @@
 //! - `serde`: Enabled by default. Adds [serde::Serialize] and
 //! [serde::Deserialize] derives, along with attributes to serialize into JSON
 //! according to the JSend specification.
+//! - `axum`: Implements [axum::response::IntoResponse] for [JSendResponse],
+//!   mapping `Success` to `200`, `Fail` to `400`, and `Error` to its `code`
+//!   field (when it is a valid 4xx/5xx status) or `500` otherwise. Pull in
+//!   [JSendResponse::with_status] to override the default status.
+//! - `utoipa`: Implements [utoipa::ToSchema] for [JSendResponse], emitting
+//!   the JSend `oneOf` schema (success/fail/error) with a `status`
+//!   discriminator, so `#[utoipa::path(responses(...))]` annotations can
+//!   reference `JSendResponse<T>`.
+//!
+//! Note that [serde_json::Value] is a hard dependency, independent of the
+//! `serde` feature: it's the default `Fail`/`Error` payload type on
+//! [JSendResponse] (and on [JSendError]), and [IntoJSendError] always
+//! produces one. Disabling `serde` only drops the [serde::Serialize] /
+//! [serde::Deserialize] derives; it doesn't make `serde_json` optional.
+
+#[cfg(feature = "axum")]
+mod axum_support;
+#[cfg(feature = "axum")]
+pub use axum_support::JSendResponseWithStatus;
+
+#[cfg(feature = "utoipa")]
+mod utoipa_support;
+
+mod result;
+pub use result::JSendError;
+
+mod error;
+pub use error::IntoJSendError;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -42,21 +71,33 @@ use serde::{Deserialize, Serialize};
 /// The `JSendResponse` enum provides a way to model JSend compliant responses.
 ///
 /// It supports the three JSend response types as variants: `Success`, `Fail`,
-/// and `Error`.
+/// and `Error`. Each variant's `data` is independently typed, since in
+/// practice they rarely share a shape: `Success`'s payload is a domain
+/// object, `Fail`'s is a field-keyed validation map the spec says SHOULD
+/// mirror POST values, and `Error`'s is a free-form diagnostic blob. `F` and
+/// `E` default to [serde_json::Value] so `JSendResponse<T>` keeps working for
+/// callers who don't need to type them individually.
+///
+/// **Breaking change:** prior releases exposed a single `data()` getter
+/// shared by all three variants, which only worked because they all shared
+/// one type parameter. Now that `Success`, `Fail`, and `Error` carry
+/// independent types, that getter has been replaced with
+/// [JSendResponse::success_data], [JSendResponse::fail_data], and
+/// [JSendResponse::error_data].
 #[derive(Debug, Clone, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(tag = "status", rename_all = "lowercase"))]
-pub enum JSendResponse<T> {
+pub enum JSendResponse<S, F = serde_json::Value, E = serde_json::Value> {
     Success {
         /// Acts as the wrapper for any data returned by the API call. If the
         /// call returns no data, `data` should be set to `None`.
-        data: Option<T>,
+        data: Option<S>,
     },
     Fail {
         /// Provides the wrapper for the details of why the request failed. If
         /// the reasons for failure correspond to POST values, the response
         /// object's keys SHOULD correspond to those POST values.
-        data: T,
+        data: F,
     },
     Error {
         /// A meaningful, end-user-readable (or at the least log-worthy)
@@ -68,23 +109,23 @@ pub enum JSendResponse<T> {
         /// A generic container for any other information about the error, i.e.
         /// the conditions that caused the error, stack traces, etc.
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
-        data: Option<T>,
+        data: Option<E>,
     },
 }
 
-impl<T> JSendResponse<T> {
+impl<S, F, E> JSendResponse<S, F, E> {
     /// Constructs the [JSendResponse::Success] variant.
-    pub fn success(data: Option<T>) -> JSendResponse<T> {
+    pub fn success(data: Option<S>) -> JSendResponse<S, F, E> {
         JSendResponse::Success { data }
     }
 
     /// Constructs the [JSendResponse::Fail] variant.
-    pub fn fail(data: T) -> JSendResponse<T> {
+    pub fn fail(data: F) -> JSendResponse<S, F, E> {
         JSendResponse::Fail { data }
     }
 
     /// Constructs the [JSendResponse::Error] variant.
-    pub fn error(message: String, code: Option<i64>, data: Option<T>) -> JSendResponse<T> {
+    pub fn error(message: String, code: Option<i64>, data: Option<E>) -> JSendResponse<S, F, E> {
         JSendResponse::Error {
             message,
             code,
@@ -92,25 +133,41 @@ impl<T> JSendResponse<T> {
         }
     }
 
-    /// Returns a reference to the underlying `Option` value if set, and `None`
-    /// otherwise.
+    /// Returns a reference to the `Success` variant's `data`, and `None` for
+    /// the other variants.
     ///
-    /// This getter "flattens" the structure of the enum:
     /// ```rust
     /// # use std::collections::HashMap;
     /// # use jsend::JSendResponse;
     /// # let data = HashMap::from([("key", "value")]);
-    /// let response_with_data = JSendResponse::success(Some(data.clone()));
-    /// assert_eq!(response_with_data.data(), Some(data).as_ref());
+    /// let response_with_data: JSendResponse<_> = JSendResponse::success(Some(data.clone()));
+    /// assert_eq!(response_with_data.success_data(), Some(data).as_ref());
     ///
-    /// let response_without_data = JSendResponse::success(None::<HashMap<&str, &str>>);
-    /// assert_eq!(response_without_data.data(), None)
+    /// let response_without_data: JSendResponse<HashMap<&str, &str>> = JSendResponse::success(None);
+    /// assert_eq!(response_without_data.success_data(), None)
     /// ```
-    pub fn data(&self) -> Option<&T> {
+    pub fn success_data(&self) -> Option<&S> {
         match self {
             JSendResponse::Success { data } => data.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the `Fail` variant's `data`, and `None` for the
+    /// other variants.
+    pub fn fail_data(&self) -> Option<&F> {
+        match self {
             JSendResponse::Fail { data } => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the `Error` variant's `data`, and `None` for
+    /// the other variants (including an `Error` with no `data` set).
+    pub fn error_data(&self) -> Option<&E> {
+        match self {
             JSendResponse::Error { data, .. } => data.as_ref(),
+            _ => None,
         }
     }
 
@@ -128,15 +185,15 @@ impl<T> JSendResponse<T> {
     ///
     /// This getter "flattens" the structure of the enum:
     /// ```rust
-    /// # use std::collections::HashMap;
     /// # use jsend::JSendResponse;
     /// # let message = "error message".to_string();
     /// # let code = 123;
-    /// # let data = HashMap::from([("key", "value")]);
-    /// let response_with_code = JSendResponse::error(message.clone(), Some(code), Some(data.clone()));
+    /// let response_with_code: JSendResponse<()> =
+    ///     JSendResponse::error(message.clone(), Some(code), None);
     /// assert_eq!(response_with_code.code(), Some(code).as_ref());
     ///
-    /// let response_without_code = JSendResponse::error(message.clone(), None, Some(data.clone()));
+    /// let response_without_code: JSendResponse<()> =
+    ///     JSendResponse::error(message.clone(), None, None);
     /// assert_eq!(response_without_code.code(), None);
     /// ```
     pub fn code(&self) -> Option<&i64> {
@@ -156,8 +213,10 @@ mod test {
     #[test]
     fn test_success_variant() {
         let data = HashMap::from([("key", "value")]);
-        let response = JSendResponse::success(Some(data.clone()));
-        assert_eq!(Some(data).as_ref(), response.data());
+        let response: JSendResponse<_> = JSendResponse::success(Some(data.clone()));
+        assert_eq!(Some(data).as_ref(), response.success_data());
+        assert_eq!(None, response.fail_data());
+        assert_eq!(None, response.error_data());
         assert_eq!(None, response.code());
         assert_eq!(None, response.message());
     }
@@ -165,7 +224,7 @@ mod test {
     #[test]
     fn test_success_variant_no_data() {
         let response: JSendResponse<HashMap<&str, &str>> = JSendResponse::success(None);
-        assert_eq!(None, response.data());
+        assert_eq!(None, response.success_data());
         assert_eq!(None, response.code());
         assert_eq!(None, response.message());
     }
@@ -173,8 +232,10 @@ mod test {
     #[test]
     fn test_fail_variant() {
         let data = HashMap::from([("key", "value")]);
-        let response = JSendResponse::fail(data.clone());
-        assert_eq!(Some(data).as_ref(), response.data());
+        let response: JSendResponse<(), _> = JSendResponse::fail(data.clone());
+        assert_eq!(None, response.success_data());
+        assert_eq!(Some(data).as_ref(), response.fail_data());
+        assert_eq!(None, response.error_data());
         assert_eq!(None, response.code());
         assert_eq!(None, response.message());
     }
@@ -182,8 +243,8 @@ mod test {
     #[test]
     fn test_fail_variant_no_data() {
         let data: Option<String> = None;
-        let response = JSendResponse::fail(data.clone());
-        assert_eq!(Some(data).as_ref(), response.data());
+        let response: JSendResponse<(), _> = JSendResponse::fail(data.clone());
+        assert_eq!(Some(data).as_ref(), response.fail_data());
         assert_eq!(None, response.code());
         assert_eq!(None, response.message());
     }
@@ -193,18 +254,20 @@ mod test {
         let message = "error message".to_string();
         let code = 123;
         let data = HashMap::from([("key", "value")]);
-        let response = JSendResponse::error(message.clone(), Some(code), Some(data.clone()));
+        let response: JSendResponse<(), (), _> =
+            JSendResponse::error(message.clone(), Some(code), Some(data.clone()));
         assert_eq!(Some(message).as_ref(), response.message());
         assert_eq!(Some(code).as_ref(), response.code());
-        assert_eq!(Some(data).as_ref(), response.data());
+        assert_eq!(Some(data).as_ref(), response.error_data());
     }
 
     #[test]
     fn test_error_variant_only_message() {
         let message = "error message".to_string();
-        let response: JSendResponse<String> = JSendResponse::error(message.clone(), None, None);
+        let response: JSendResponse<(), (), String> =
+            JSendResponse::error(message.clone(), None, None);
         assert_eq!(Some(message).as_ref(), response.message());
         assert_eq!(None, response.code());
-        assert_eq!(None, response.data());
+        assert_eq!(None, response.error_data());
     }
 }
@@ -0,0 +1,89 @@
+//! Conversions between [JSendResponse] and [Result], so client and handler
+//! code can lean on idiomatic Rust error handling instead of matching on the
+//! enum directly.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+use crate::JSendResponse;
+
+impl<S, F, E> JSendResponse<S, F, E> {
+    /// Collapses this response into a [Result], treating `Success` as `Ok`
+    /// and both `Fail` and `Error` as [JSendError].
+    ///
+    /// ```rust
+    /// # use jsend::JSendResponse;
+    /// let response: JSendResponse<_> = JSendResponse::success(Some("ok"));
+    /// assert_eq!(response.into_result().unwrap(), Some("ok"));
+    ///
+    /// let response: JSendResponse<(), &str> = JSendResponse::fail("bad request");
+    /// assert!(response.into_result().is_err());
+    /// ```
+    pub fn into_result(self) -> Result<Option<S>, JSendError<F, E>> {
+        match self {
+            JSendResponse::Success { data } => Ok(data),
+            JSendResponse::Fail { data } => Err(JSendError::Fail { data }),
+            JSendResponse::Error {
+                message,
+                code,
+                data,
+            } => Err(JSendError::Error {
+                message,
+                code,
+                data,
+            }),
+        }
+    }
+
+    /// Builds a response from a [Result], mapping `Ok` to `Success` and `Err`
+    /// to `Error` with `message` set from the error's [Display](fmt::Display)
+    /// representation.
+    ///
+    /// ```rust
+    /// # use jsend::JSendResponse;
+    /// # use std::num::ParseIntError;
+    /// let result: Result<i32, ParseIntError> = "not a number".parse();
+    /// let response: JSendResponse<_> = JSendResponse::from_result(result);
+    /// assert_eq!(response.message(), Some(&"invalid digit found in string".to_string()));
+    /// ```
+    pub fn from_result<Er>(result: Result<S, Er>) -> JSendResponse<S, F, E>
+    where
+        Er: StdError,
+    {
+        match result {
+            Ok(data) => JSendResponse::success(Some(data)),
+            Err(error) => JSendResponse::error(error.to_string(), None, None),
+        }
+    }
+}
+
+/// The failure counterpart of [JSendResponse], produced by
+/// [JSendResponse::into_result] for the `Fail` and `Error` variants.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JSendError<F, E = serde_json::Value> {
+    /// Carries the `data` payload of a [JSendResponse::Fail].
+    Fail {
+        /// See [JSendResponse::Fail::data](JSendResponse#variant.Fail).
+        data: F,
+    },
+    /// Carries the `message`, `code`, and `data` of a [JSendResponse::Error].
+    Error {
+        /// See [JSendResponse::Error::message](JSendResponse#variant.Error).
+        message: String,
+        /// See [JSendResponse::Error::code](JSendResponse#variant.Error).
+        code: Option<i64>,
+        /// See [JSendResponse::Error::data](JSendResponse#variant.Error).
+        data: Option<E>,
+    },
+}
+
+impl<F, E> fmt::Display for JSendError<F, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JSendError::Fail { .. } => write!(f, "request failed"),
+            JSendError::Error { message, .. } => write!(f, "{message}"),
+        }
+    }
+}
+
+impl<F: fmt::Debug, E: fmt::Debug> StdError for JSendError<F, E> {}
@@ -0,0 +1,104 @@
+//! Integration with [utoipa](https://crates.io/crates/utoipa) 5.x, gated
+//! behind the `utoipa` feature.
+//!
+//! [JSendResponse] has a hand-written [utoipa::ToSchema] impl rather than a
+//! derive, since its serde `tag = "status"` representation needs a `oneOf`
+//! schema with a discriminator, which the three variants don't map onto
+//! automatically.
+
+use std::borrow::Cow;
+
+use utoipa::openapi::{
+    schema::{ObjectBuilder, SchemaType, Type},
+    Discriminator, OneOfBuilder, RefOr, Schema,
+};
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::JSendResponse;
+
+impl<S, F, E> PartialSchema for JSendResponse<S, F, E>
+where
+    S: ToSchema,
+    F: ToSchema,
+    E: ToSchema,
+{
+    fn schema() -> RefOr<Schema> {
+        let success = ObjectBuilder::new()
+            .property("status", status_schema("success"))
+            .required("status")
+            .property("data", nullable(S::schema()))
+            .required("data")
+            .build();
+
+        let fail = ObjectBuilder::new()
+            .property("status", status_schema("fail"))
+            .required("status")
+            .property("data", F::schema())
+            .required("data")
+            .build();
+
+        let error = ObjectBuilder::new()
+            .property("status", status_schema("error"))
+            .required("status")
+            .property(
+                "message",
+                ObjectBuilder::new().schema_type(SchemaType::Type(Type::String)),
+            )
+            .required("message")
+            .property(
+                "code",
+                ObjectBuilder::new().schema_type(SchemaType::Type(Type::Integer)),
+            )
+            .property("data", E::schema())
+            .build();
+
+        Schema::OneOf(
+            OneOfBuilder::new()
+                .item(success)
+                .item(fail)
+                .item(error)
+                .discriminator(Some(Discriminator::new("status")))
+                .build(),
+        )
+        .into()
+    }
+}
+
+impl<S, F, E> ToSchema for JSendResponse<S, F, E>
+where
+    S: ToSchema,
+    F: ToSchema,
+    E: ToSchema,
+{
+    /// Includes each type parameter's own schema name so that, e.g.,
+    /// `JSendResponse<Post>` and `JSendResponse<Comment>` land as distinct
+    /// schemas in the OpenAPI components map instead of colliding under one
+    /// `JSendResponse` entry.
+    fn name() -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "JSendResponse_{}_{}_{}",
+            S::name(),
+            F::name(),
+            E::name()
+        ))
+    }
+}
+
+fn status_schema(value: &'static str) -> Schema {
+    ObjectBuilder::new()
+        .schema_type(SchemaType::Type(Type::String))
+        .enum_values(Some([value]))
+        .build()
+        .into()
+}
+
+/// Widens a schema to also accept JSON `null`, matching `Success.data`'s
+/// `Option<S>` serializing to `null` when unset.
+fn nullable(schema: RefOr<Schema>) -> RefOr<Schema> {
+    let null_schema: Schema = ObjectBuilder::new()
+        .schema_type(SchemaType::Type(Type::Null))
+        .build()
+        .into();
+
+    Schema::OneOf(OneOfBuilder::new().item(schema).item(null_schema).build()).into()
+}
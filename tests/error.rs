@@ -0,0 +1,81 @@
+//! Tests for the [IntoJSendError] bridge from application error types into
+//! [JSendResponse::Error].
+use std::fmt;
+
+use jsend::{IntoJSendError, JSendResponse};
+use serde_json::json;
+
+#[derive(Debug)]
+struct DbError;
+
+impl fmt::Display for DbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "connection refused")
+    }
+}
+
+impl std::error::Error for DbError {}
+
+#[derive(Debug)]
+enum AppError {
+    NotFound,
+    Database(DbError),
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::NotFound => write!(f, "resource not found"),
+            AppError::Database(_) => write!(f, "a database error occurred"),
+        }
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AppError::NotFound => None,
+            AppError::Database(err) => Some(err),
+        }
+    }
+}
+
+impl IntoJSendError for AppError {
+    fn code(&self) -> Option<i64> {
+        match self {
+            AppError::NotFound => Some(404),
+            AppError::Database(_) => Some(500),
+        }
+    }
+}
+
+#[test]
+fn test_to_jsend_error_without_source() {
+    let response: JSendResponse<(), ()> = AppError::NotFound.to_jsend_error();
+    assert_eq!(
+        response,
+        JSendResponse::error("resource not found".to_string(), Some(404), None)
+    );
+}
+
+#[test]
+fn test_to_jsend_error_with_source_chain() {
+    let response: JSendResponse<(), ()> = AppError::Database(DbError).to_jsend_error();
+    assert_eq!(
+        response,
+        JSendResponse::error(
+            "a database error occurred".to_string(),
+            Some(500),
+            Some(json!(["connection refused"])),
+        )
+    );
+}
+
+#[test]
+fn test_blanket_from_impl() {
+    let response: JSendResponse<()> = AppError::NotFound.into();
+    assert_eq!(
+        response,
+        JSendResponse::error("resource not found".to_string(), Some(404), None)
+    );
+}
@@ -0,0 +1,64 @@
+//! Tests for the [Result] interop provided by `JSendResponse::into_result`
+//! and `JSendResponse::from_result`.
+use std::fmt;
+
+use jsend::{JSendError, JSendResponse};
+
+#[derive(Debug)]
+struct MyError;
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "something went wrong")
+    }
+}
+
+impl std::error::Error for MyError {}
+
+#[test]
+fn test_into_result_success() {
+    let response: JSendResponse<&str> = JSendResponse::success(Some("value"));
+    assert_eq!(response.into_result(), Ok(Some("value")));
+}
+
+#[test]
+fn test_into_result_fail() {
+    let response: JSendResponse<(), &str> = JSendResponse::fail("bad request");
+    assert_eq!(
+        response.into_result(),
+        Err(JSendError::Fail {
+            data: "bad request"
+        })
+    );
+}
+
+#[test]
+fn test_into_result_error() {
+    let response: JSendResponse<(), (), &str> =
+        JSendResponse::error("unavailable".to_string(), Some(503), None);
+    assert_eq!(
+        response.into_result(),
+        Err(JSendError::Error {
+            message: "unavailable".to_string(),
+            code: Some(503),
+            data: None,
+        })
+    );
+}
+
+#[test]
+fn test_from_result_ok() {
+    let result: Result<i32, MyError> = Ok(42);
+    let response: JSendResponse<i32> = JSendResponse::from_result(result);
+    assert_eq!(response, JSendResponse::success(Some(42)));
+}
+
+#[test]
+fn test_from_result_err() {
+    let result: Result<i32, MyError> = Err(MyError);
+    let response: JSendResponse<i32> = JSendResponse::from_result(result);
+    assert_eq!(
+        response,
+        JSendResponse::error("something went wrong".to_string(), None, None)
+    );
+}